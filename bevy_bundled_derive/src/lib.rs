@@ -3,7 +3,7 @@ use heck::{AsPascalCase, AsSnakeCase};
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Fields, FieldsNamed, Item};
+use syn::{parse_macro_input, Fields, FieldsNamed, FieldsUnnamed, Index, Item};
 
 /// # `Bundled` Derive Macro
 ///
@@ -22,6 +22,11 @@ use syn::{parse_macro_input, Fields, FieldsNamed, Item};
 /// attributes), the struct will have a generated type `Self::Marker`, corrosponding to a marker
 /// component generated and initialized for the struct.
 ///
+/// Tuple structs are supported the same way, with fields named positionally: a tuple struct's
+/// first field is `Self::Field0`, its second `Self::Field1`, and so on. Unit structs are also
+/// supported; since they have no fields, `Self::Bundled` reduces to just the marker component (or
+/// an empty bundle if `#[unmarked]`).
+///
 /// ### Attributes
 ///   * `#[marked]`: Struct level attribute indicating a struct should have an additional generated marker
 ///   component for easy
@@ -133,6 +138,18 @@ pub fn bundle(input: TokenStream) -> TokenStream {
             } else {
                 None
             };
+
+            // Tuple-struct flavored counterparts of `marker_field`/`marker_from`, used when the
+            // bundle itself has no named fields (i.e. the source struct is a tuple or unit struct).
+            let marker_field_tuple = if marked {
+                Some(quote! {
+                    #marker_ident,
+                })
+            } else {
+                None
+            };
+            let marker_from_tuple = marker_field_tuple.clone();
+
             match st.fields {
                 Fields::Named(FieldsNamed {
                     brace_token: _,
@@ -208,7 +225,112 @@ pub fn bundle(input: TokenStream) -> TokenStream {
                     }
                     .into()
                 }
-                _ => unreachable!(),
+                Fields::Unnamed(FieldsUnnamed {
+                    paren_token: _,
+                    ref unnamed,
+                }) => {
+                    let unnamed = unnamed.into_iter();
+
+                    let fields_trait_impl = unnamed.clone().enumerate().map(|(i, x)| {
+                        let field_ident = format_ident!("Field{}", i);
+                        let field_ty = &x.ty;
+                        quote! {
+                        pub type #field_ident =
+                            #mod_ident::#component_ident<#i, #field_ty>;
+                        }
+                    });
+
+                    let bundle_inner_ident = unnamed.clone().enumerate().map(|(i, x)| {
+                        let field_ty = &x.ty;
+                        quote! {
+                            #component_ident<#i, #field_ty>,
+                        }
+                    });
+
+                    let from_inner = unnamed.clone().enumerate().map(|(i, _)| {
+                        let index = Index::from(i);
+                        quote! {
+                            #component_ident::<#i, _>(item.#index),
+                        }
+                    });
+
+                    quote! {
+
+                    #[automatically_derived]
+                    impl #ident {
+                        #(#fields_trait_impl)*
+                        pub type Bundled = #mod_ident::#bundle_ident;
+                        #marker_alias
+
+                        pub fn bundled(self) -> Self::Bundled {
+                            self.into()
+                        }
+                    }
+
+
+                    pub mod #mod_ident {
+                        use super::*;
+                        #marker_declaration
+
+                        #[derive(bevy::ecs::bundle::Bundle)]
+                        pub struct #bundle_ident(
+                            #(#bundle_inner_ident)*
+                            #marker_field_tuple
+                        );
+
+                        #[automatically_derived]
+                        impl From<super::#ident> for #bundle_ident {
+                            fn from(item: super::#ident) -> #bundle_ident {
+                                #bundle_ident(
+                                    #(#from_inner)*
+                                    #marker_from_tuple
+                                )
+                            }
+
+                        }
+
+                        #[derive(bevy::prelude::Deref, bevy::prelude::DerefMut, bevy::ecs::component::Component)]
+                        pub struct #component_ident<const FIELD: usize, T>(pub(super) T);
+                    }
+                    }
+                    .into()
+                }
+                Fields::Unit => {
+                    quote! {
+
+                    #[automatically_derived]
+                    impl #ident {
+                        pub type Bundled = #mod_ident::#bundle_ident;
+                        #marker_alias
+
+                        pub fn bundled(self) -> Self::Bundled {
+                            self.into()
+                        }
+                    }
+
+
+                    pub mod #mod_ident {
+                        use super::*;
+                        #marker_declaration
+
+                        #[derive(bevy::ecs::bundle::Bundle)]
+                        pub struct #bundle_ident(
+                            #marker_field_tuple
+                        );
+
+                        #[automatically_derived]
+                        impl From<super::#ident> for #bundle_ident {
+                            fn from(_item: super::#ident) -> #bundle_ident {
+                                #bundle_ident(
+                                    #marker_from_tuple
+                                )
+                            }
+
+                        }
+                    }
+                    }
+                    .into()
+                }
             }
         }
         _ => unreachable!(),
@@ -230,6 +352,9 @@ pub fn bundle(input: TokenStream) -> TokenStream {
 /// field `health`, would have a corrosponding `Self::Health`, which can be accessed with a normal
 /// `Res` or `ResMut` query.
 ///
+/// Tuple structs are supported the same way, with fields named positionally (`Self::Field0`,
+/// `Self::Field1`, ...). Unit structs are also supported, and insert no resources at all.
+///
 /// ## Examples
 /// ```
 /// use bevy::prelude::*;
@@ -354,7 +479,75 @@ pub fn resource_bundle(input: TokenStream) -> TokenStream {
                     }
                     .into()
                 }
-                _ => unreachable!(),
+                Fields::Unnamed(FieldsUnnamed {
+                    paren_token: _,
+                    ref unnamed,
+                }) => {
+                    let unnamed = unnamed.into_iter();
+
+                    let fields_trait_impl = unnamed.clone().enumerate().map(|(i, x)| {
+                        let field_ident = format_ident!("Field{}", i);
+                        let field_ty = &x.ty;
+                        quote! {
+                        pub type #field_ident =
+                            #mod_ident::#component_ident<#i, #field_ty>;
+                        }
+                    });
+
+                    let insert_self_inner = unnamed.clone().enumerate().map(|(i, _)| {
+                        let index = Index::from(i);
+                        quote! {
+                            .insert_resource(#mod_ident::#component_ident::<#i, _>(self.#index))
+                        }
+                    });
+
+                    let insert_self_commands_inner = unnamed.clone().enumerate().map(|(i, _)| {
+                        let index = Index::from(i);
+                        quote! {
+                            commands.insert_resource(#mod_ident::#component_ident::<#i, _>(self.#index));
+                        }
+                    });
+
+                    quote! {
+
+                    #[automatically_derived]
+                    impl #ident {
+                        #(#fields_trait_impl)*
+                    }
+
+                    #[automatically_derived]
+                    impl bevy_bundled::ResourceBundle for #ident {
+                        fn insert_self_app(&self, app: &mut bevy::prelude::App) {
+                            app
+                                #(#insert_self_inner)*;
+                        }
+
+                        fn insert_self_commands(&self, commands: &mut bevy::prelude::Commands) {
+                            #(#insert_self_commands_inner)*
+                        }
+                    }
+
+                    pub mod #mod_ident {
+                        use super::*;
+
+                        #[derive(bevy::prelude::Deref, bevy::prelude::DerefMut, bevy::ecs::prelude::Resource)]
+                        pub struct #component_ident<const FIELD: usize, T>(pub(super) T);
+                    }
+                    }
+                    .into()
+                }
+                Fields::Unit => {
+                    quote! {
+
+                    #[automatically_derived]
+                    impl bevy_bundled::ResourceBundle for #ident {
+                        fn insert_self_app(&self, _app: &mut bevy::prelude::App) {}
+
+                        fn insert_self_commands(&self, _commands: &mut bevy::prelude::Commands) {}
+                    }
+                    }
+                    .into()
+                }
             }
         }
         _ => unreachable!(),